@@ -0,0 +1,726 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeZone as ChronoTimeZone};
+use polars_core::prelude::*;
+use polars_time::prelude::*;
+
+use super::*;
+
+/// How `duration()` should handle a component scaling step that overflows `i64`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DurationOverflowBehavior {
+    /// Wrap on overflow, matching `i64`'s default wrapping arithmetic (current behavior).
+    #[default]
+    Wrap,
+    /// Clamp to `i64::MIN`/`i64::MAX` on overflow.
+    Saturate,
+    /// Set the result to null on overflow.
+    Null,
+    /// Raise an error on overflow.
+    Raise,
+}
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TemporalFunction {
+    DatetimeFunction {
+        time_unit: TimeUnit,
+        time_zone: Option<TimeZone>,
+    },
+    DatetimeFromIsoWeek {
+        time_unit: TimeUnit,
+        time_zone: Option<TimeZone>,
+    },
+    DatetimeFromOrdinal {
+        time_unit: TimeUnit,
+        time_zone: Option<TimeZone>,
+    },
+    Duration {
+        time_unit: TimeUnit,
+        overflow: DurationOverflowBehavior,
+    },
+    DatetimeFromDateTime {
+        time_unit: TimeUnit,
+        time_zone: Option<TimeZone>,
+    },
+}
+
+impl fmt::Display for TemporalFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TemporalFunction::*;
+        let s = match self {
+            DatetimeFunction { .. } => "datetime",
+            DatetimeFromIsoWeek { .. } => "datetime_from_iso_week",
+            DatetimeFromOrdinal { .. } => "datetime_from_ordinal",
+            Duration { .. } => "duration",
+            DatetimeFromDateTime { .. } => "datetime_from_date_time",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// How a naive local datetime was localized against a time zone by [`localize_datetime`].
+enum Localized {
+    /// Unambiguous, or an ambiguous datetime resolved to a concrete instant by the `ambiguous` policy.
+    Valid(NaiveDateTime),
+    /// Ambiguous datetime resolved to null by `ambiguous = "null"`.
+    Null,
+    /// Falls in a spring-forward DST transition gap; no valid local-to-UTC mapping exists. Left for the
+    /// caller to resolve via its own `non_existent` policy.
+    NonExistentGap,
+}
+
+/// Localizes a naive local datetime against `tz`, resolving an ambiguous (DST fall-back) result per
+/// `ambiguous`. A non-existent (DST spring-forward gap) result is *not* resolved here - the caller applies
+/// its own `non_existent` policy, mirroring how `ambiguous` and `non_existent` are independent knobs on
+/// `DatetimeArgs`.
+fn localize_datetime(naive: NaiveDateTime, tz: &Tz, ambiguous: &str) -> PolarsResult<Localized> {
+    use chrono::offset::LocalResult;
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Ok(Localized::Valid(dt.naive_utc())),
+        LocalResult::None => Ok(Localized::NonExistentGap),
+        LocalResult::Ambiguous(earliest, latest) => match ambiguous {
+            "earliest" => Ok(Localized::Valid(earliest.naive_utc())),
+            "latest" => Ok(Localized::Valid(latest.naive_utc())),
+            "null" => Ok(Localized::Null),
+            "raise" => {
+                polars_bail!(
+                    ComputeError:
+                    "datetime '{naive}' is ambiguous in time zone '{tz}': falls in a DST \
+                     fall-back transition. Use ambiguous = \"earliest\"/\"latest\"/\"null\" to resolve it"
+                )
+            },
+            policy => polars_bail!(ComputeError: "unknown ambiguous policy: '{policy}'"),
+        },
+    }
+}
+
+/// Resolves a naive local datetime to a concrete UTC instant, applying the `ambiguous` policy to a
+/// DST fall-back result and the `non_existent` policy to a DST spring-forward gap. Shared by every
+/// kernel that builds a `Datetime` from components and optionally localizes it (`datetime_function`,
+/// `datetime_from_iso_week_function`, `datetime_from_ordinal_function`,
+/// `datetime_from_date_time_function`).
+fn resolve_naive(
+    naive: NaiveDateTime,
+    tz: Option<&Tz>,
+    ambiguous: &str,
+    non_existent: &str,
+) -> PolarsResult<Option<NaiveDateTime>> {
+    let Some(tz) = tz else {
+        return Ok(Some(naive));
+    };
+    match localize_datetime(naive, tz, ambiguous)? {
+        Localized::Valid(dt) => Ok(Some(dt)),
+        Localized::Null => Ok(None),
+        Localized::NonExistentGap => match non_existent {
+            "null" => Ok(None),
+            "raise" => polars_bail!(
+                ComputeError:
+                "datetime '{naive}' is non-existent in time zone '{tz}': falls in a DST \
+                 spring-forward transition gap. Use non_existent = \"null\" to set it to null \
+                 instead of raising"
+            ),
+            policy => polars_bail!(ComputeError: "unknown non_existent policy: '{policy}'"),
+        },
+    }
+}
+
+fn naive_to_timestamp(naive: NaiveDateTime, time_unit: TimeUnit) -> Option<i64> {
+    let dt = naive.and_utc();
+    let seconds = dt.timestamp();
+    let subsec_nanos = dt.timestamp_subsec_nanos() as i64;
+    match time_unit {
+        TimeUnit::Milliseconds => seconds
+            .checked_mul(1_000)?
+            .checked_add(subsec_nanos / 1_000_000),
+        TimeUnit::Microseconds => seconds
+            .checked_mul(1_000_000)?
+            .checked_add(subsec_nanos / 1_000),
+        TimeUnit::Nanoseconds => seconds.checked_mul(1_000_000_000)?.checked_add(subsec_nanos),
+    }
+}
+
+/// Builds a naive datetime from the numeric part columns, then (if `time_zone` is set) localizes it,
+/// resolving the `ambiguous`/`non_existent` policies row by row.
+///
+/// `input` must be `[year, month, day, hour, minute, second, microsecond, ambiguous, non_existent]`,
+/// matching the order `datetime()` assembles in `dsl::functions::temporal`.
+pub(super) fn datetime_function(
+    input: &[Column],
+    time_unit: TimeUnit,
+    time_zone: Option<&TimeZone>,
+) -> PolarsResult<Column> {
+    let year = input[0].i32()?;
+    let month = input[1].i32()?;
+    let day = input[2].i32()?;
+    let hour = input[3].i32()?;
+    let minute = input[4].i32()?;
+    let second = input[5].i32()?;
+    let microsecond = input[6].i32()?;
+    let ambiguous = input[7].str()?;
+    let non_existent = input[8].str()?;
+
+    let len = year.len();
+    let mut out = Int64ChunkedBuilder::new(PlSmallStr::from_static("datetime"), len, len);
+    let tz: Option<Tz> = time_zone.map(|tz| tz.parse()).transpose()?;
+
+    for i in 0..len {
+        let opt_naive = (|| -> Option<NaiveDateTime> {
+            let date =
+                NaiveDate::from_ymd_opt(year.get(i)?, month.get(i)? as u32, day.get(i)? as u32)?;
+            let time = NaiveTime::from_hms_micro_opt(
+                hour.get(i)? as u32,
+                minute.get(i)? as u32,
+                second.get(i)? as u32,
+                microsecond.get(i)? as u32,
+            )?;
+            Some(NaiveDateTime::new(date, time))
+        })();
+
+        let Some(naive) = opt_naive else {
+            out.append_null();
+            continue;
+        };
+
+        let resolved = resolve_naive(
+            naive,
+            tz.as_ref(),
+            ambiguous.get(i).unwrap_or("raise"),
+            non_existent.get(i).unwrap_or("raise"),
+        )?;
+
+        match resolved.and_then(|dt| naive_to_timestamp(dt, time_unit)) {
+            Some(ts) => out.append_value(ts),
+            None => out.append_null(),
+        }
+    }
+
+    Ok(out
+        .finish()
+        .into_series()
+        .cast(&DataType::Datetime(time_unit, time_zone.cloned()))?
+        .into())
+}
+
+/// Maps an ISO weekday number (`1..=7`, Monday = 1) to [`chrono::Weekday`].
+fn iso_weekday(weekday: i32) -> Option<chrono::Weekday> {
+    match weekday {
+        1 => Some(chrono::Weekday::Mon),
+        2 => Some(chrono::Weekday::Tue),
+        3 => Some(chrono::Weekday::Wed),
+        4 => Some(chrono::Weekday::Thu),
+        5 => Some(chrono::Weekday::Fri),
+        6 => Some(chrono::Weekday::Sat),
+        7 => Some(chrono::Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Builds a naive datetime from an ISO week date (`year`, `week`, `weekday`) plus time-of-day
+/// components, then (if `time_zone` is set) localizes it as [`datetime_function`] does.
+///
+/// `input` must be `[year, week, weekday, hour, minute, second, microsecond, ambiguous, non_existent]`,
+/// matching the order `datetime_from_iso_week()` assembles in `dsl::functions::temporal`. `weekday` must
+/// be in `1..=7` (Monday = 1); a `week`/`weekday` combination that doesn't exist in `year`'s ISO calendar
+/// (including a 53rd week for a year that only has 52) produces null, same as an out-of-range month/day
+/// does for `datetime_function`.
+pub(super) fn datetime_from_iso_week_function(
+    input: &[Column],
+    time_unit: TimeUnit,
+    time_zone: Option<&TimeZone>,
+) -> PolarsResult<Column> {
+    let year = input[0].i32()?;
+    let week = input[1].i32()?;
+    let weekday = input[2].i32()?;
+    let hour = input[3].i32()?;
+    let minute = input[4].i32()?;
+    let second = input[5].i32()?;
+    let microsecond = input[6].i32()?;
+    let ambiguous = input[7].str()?;
+    let non_existent = input[8].str()?;
+
+    let len = year.len();
+    let mut out = Int64ChunkedBuilder::new(PlSmallStr::from_static("datetime"), len, len);
+    let tz: Option<Tz> = time_zone.map(|tz| tz.parse()).transpose()?;
+
+    for i in 0..len {
+        let opt_naive = (|| -> Option<NaiveDateTime> {
+            let y = year.get(i)?;
+            let w = week.get(i)?;
+            let d = iso_weekday(weekday.get(i)?)?;
+            let date = NaiveDate::from_isoywd_opt(y, w.try_into().ok()?, d)?;
+            let time = NaiveTime::from_hms_micro_opt(
+                hour.get(i)? as u32,
+                minute.get(i)? as u32,
+                second.get(i)? as u32,
+                microsecond.get(i)? as u32,
+            )?;
+            Some(NaiveDateTime::new(date, time))
+        })();
+
+        let Some(naive) = opt_naive else {
+            out.append_null();
+            continue;
+        };
+
+        let resolved = resolve_naive(
+            naive,
+            tz.as_ref(),
+            ambiguous.get(i).unwrap_or("raise"),
+            non_existent.get(i).unwrap_or("raise"),
+        )?;
+
+        match resolved.and_then(|dt| naive_to_timestamp(dt, time_unit)) {
+            Some(ts) => out.append_value(ts),
+            None => out.append_null(),
+        }
+    }
+
+    Ok(out
+        .finish()
+        .into_series()
+        .cast(&DataType::Datetime(time_unit, time_zone.cloned()))?
+        .into())
+}
+
+/// Builds a naive datetime from an ordinal date (`year`, `ordinal`) plus time-of-day components,
+/// then (if `time_zone` is set) localizes it as [`datetime_function`] does.
+///
+/// `input` must be `[year, ordinal, hour, minute, second, microsecond, ambiguous, non_existent]`,
+/// matching the order `datetime_from_ordinal()` assembles in `dsl::functions::temporal`. `ordinal`
+/// must be in `1..=365` (`1..=366` in leap years); out of range produces null.
+pub(super) fn datetime_from_ordinal_function(
+    input: &[Column],
+    time_unit: TimeUnit,
+    time_zone: Option<&TimeZone>,
+) -> PolarsResult<Column> {
+    let year = input[0].i32()?;
+    let ordinal = input[1].i32()?;
+    let hour = input[2].i32()?;
+    let minute = input[3].i32()?;
+    let second = input[4].i32()?;
+    let microsecond = input[5].i32()?;
+    let ambiguous = input[6].str()?;
+    let non_existent = input[7].str()?;
+
+    let len = year.len();
+    let mut out = Int64ChunkedBuilder::new(PlSmallStr::from_static("datetime"), len, len);
+    let tz: Option<Tz> = time_zone.map(|tz| tz.parse()).transpose()?;
+
+    for i in 0..len {
+        let opt_naive = (|| -> Option<NaiveDateTime> {
+            let date = NaiveDate::from_yo_opt(year.get(i)?, ordinal.get(i)? as u32)?;
+            let time = NaiveTime::from_hms_micro_opt(
+                hour.get(i)? as u32,
+                minute.get(i)? as u32,
+                second.get(i)? as u32,
+                microsecond.get(i)? as u32,
+            )?;
+            Some(NaiveDateTime::new(date, time))
+        })();
+
+        let Some(naive) = opt_naive else {
+            out.append_null();
+            continue;
+        };
+
+        let resolved = resolve_naive(
+            naive,
+            tz.as_ref(),
+            ambiguous.get(i).unwrap_or("raise"),
+            non_existent.get(i).unwrap_or("raise"),
+        )?;
+
+        match resolved.and_then(|dt| naive_to_timestamp(dt, time_unit)) {
+            Some(ts) => out.append_value(ts),
+            None => out.append_null(),
+        }
+    }
+
+    Ok(out
+        .finish()
+        .into_series()
+        .cast(&DataType::Datetime(time_unit, time_zone.cloned()))?
+        .into())
+}
+
+/// Scales `value` by `factor` and accumulates it into `acc`, applying `overflow` if either the
+/// multiply or the add overflows `i64`.
+fn checked_step(
+    acc: i64,
+    value: i64,
+    factor: i64,
+    overflow: DurationOverflowBehavior,
+) -> PolarsResult<Option<i64>> {
+    if let Some(result) = value.checked_mul(factor).and_then(|scaled| acc.checked_add(scaled)) {
+        return Ok(Some(result));
+    }
+    match overflow {
+        DurationOverflowBehavior::Wrap => Ok(Some(acc.wrapping_add(value.wrapping_mul(factor)))),
+        // widen to i128 so the term is folded into the running total before clamping - saturating
+        // `value * factor` to i64 first (then adding `acc`) would throw away the term's true
+        // magnitude and could make a dominant term lose to a smaller one of the opposite sign
+        DurationOverflowBehavior::Saturate => {
+            let widened = (acc as i128) + (value as i128) * (factor as i128);
+            Ok(Some(widened.clamp(i64::MIN as i128, i64::MAX as i128) as i64))
+        },
+        DurationOverflowBehavior::Null => Ok(None),
+        DurationOverflowBehavior::Raise => polars_bail!(
+            ComputeError:
+            "duration component overflowed i64 when scaled into the target time unit; use \
+             overflow = \"saturate\" or \"null\" to avoid raising"
+        ),
+    }
+}
+
+/// Scales every component of a `duration()` call into `time_unit` and accumulates them into a
+/// single `i64`, applying `overflow` to any step that overflows (the baseline `wrap` behavior
+/// matches plain `i64` wrapping arithmetic, which silently produced garbage durations before this
+/// policy existed). `saturate` sums all terms exactly and clamps the total once, at the end.
+///
+/// `input` must be `[weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds]`,
+/// matching the order `duration()` assembles in `dsl::functions::temporal`.
+pub(super) fn duration_function(
+    input: &[Column],
+    time_unit: TimeUnit,
+    overflow: DurationOverflowBehavior,
+) -> PolarsResult<Column> {
+    let weeks = input[0].i64()?;
+    let days = input[1].i64()?;
+    let hours = input[2].i64()?;
+    let minutes = input[3].i64()?;
+    let seconds = input[4].i64()?;
+    let milliseconds = input[5].i64()?;
+    let microseconds = input[6].i64()?;
+    let nanoseconds = input[7].i64()?;
+
+    let tu_per_sec: i64 = match time_unit {
+        TimeUnit::Nanoseconds => 1_000_000_000,
+        TimeUnit::Microseconds => 1_000_000,
+        TimeUnit::Milliseconds => 1_000,
+    };
+    // seconds-granularity components: factor is always well within i64 range, so a plain multiply
+    // (not checked) is enough to compute it
+    let whole_second_components = [
+        (weeks, 7 * 86_400 * tu_per_sec),
+        (days, 86_400 * tu_per_sec),
+        (hours, 3_600 * tu_per_sec),
+        (minutes, 60 * tu_per_sec),
+        (seconds, tu_per_sec),
+    ];
+    // sub-second components are expressed directly in terms of `time_unit` (rather than via an
+    // intermediate seconds step, which would truncate them); a component finer than `time_unit` is
+    // divided down to it, truncating toward zero
+    let (ms_factor, us_factor, us_divisor, ns_divisor) = match time_unit {
+        TimeUnit::Nanoseconds => (1_000_000, 1_000, 1, 1),
+        TimeUnit::Microseconds => (1_000, 1, 1, 1_000),
+        TimeUnit::Milliseconds => (1, 1, 1_000, 1_000_000),
+    };
+
+    let len = weeks.len();
+    let mut out = Int64ChunkedBuilder::new(PlSmallStr::from_static("duration"), len, len);
+
+    for i in 0..len {
+        let row = (|| -> PolarsResult<Option<i64>> {
+            if overflow == DurationOverflowBehavior::Saturate {
+                // sum every term exactly in i128 and clamp once at the end, so a term clamped early
+                // can't lose its magnitude to a later term of the opposite sign
+                let total = (|| -> Option<i128> {
+                    let mut total = (nanoseconds.get(i)? / ns_divisor) as i128
+                        + (microseconds.get(i)? / us_divisor) as i128 * us_factor as i128;
+                    let terms = whole_second_components.into_iter().chain([(milliseconds, ms_factor)]);
+                    for (component, factor) in terms {
+                        total += component.get(i)? as i128 * factor as i128;
+                    }
+                    Some(total)
+                })();
+                return Ok(total.map(|t| t.clamp(i64::MIN as i128, i64::MAX as i128) as i64));
+            }
+            let mut acc: i64 = 0;
+            for (component, factor) in whole_second_components {
+                let Some(value) = component.get(i) else {
+                    return Ok(None);
+                };
+                match checked_step(acc, value, factor, overflow)? {
+                    Some(new_acc) => acc = new_acc,
+                    None => return Ok(None),
+                }
+            }
+
+            let Some(ms) = milliseconds.get(i) else {
+                return Ok(None);
+            };
+            match checked_step(acc, ms, ms_factor, overflow)? {
+                Some(new_acc) => acc = new_acc,
+                None => return Ok(None),
+            }
+
+            let Some(us) = microseconds.get(i) else {
+                return Ok(None);
+            };
+            match checked_step(acc, us / us_divisor, us_factor, overflow)? {
+                Some(new_acc) => acc = new_acc,
+                None => return Ok(None),
+            }
+
+            // nanoseconds are always finer than or equal to `time_unit`, so folding them in is a
+            // division that can't itself overflow - only the final accumulation can
+            let Some(ns) = nanoseconds.get(i) else {
+                return Ok(None);
+            };
+            let ns_in_tu = ns / ns_divisor;
+            match acc.checked_add(ns_in_tu) {
+                Some(new_acc) => Ok(Some(new_acc)),
+                None => match overflow {
+                    DurationOverflowBehavior::Wrap => Ok(Some(acc.wrapping_add(ns_in_tu))),
+                    DurationOverflowBehavior::Saturate => Ok(Some(acc.saturating_add(ns_in_tu))),
+                    DurationOverflowBehavior::Null => Ok(None),
+                    DurationOverflowBehavior::Raise => polars_bail!(
+                        ComputeError:
+                        "duration overflowed i64 while accumulating nanoseconds; use \
+                         overflow = \"saturate\" or \"null\" to avoid raising"
+                    ),
+                },
+            }
+        })()?;
+
+        match row {
+            Some(total) => out.append_value(total),
+            None => out.append_null(),
+        }
+    }
+
+    Ok(out
+        .finish()
+        .into_series()
+        .cast(&DataType::Duration(time_unit))?
+        .into())
+}
+
+/// Days from 0001-01-01 (chrono's proleptic Gregorian "common era" day 1) to the Unix epoch
+/// (1970-01-01), i.e. `NaiveDate::from_num_days_from_ce(UNIX_EPOCH_DAY) == 1970-01-01`.
+const UNIX_EPOCH_DAY: i32 = 719_163;
+
+/// Splices a `Date` column (days since the Unix epoch) with a `Time` column (nanoseconds since
+/// midnight) into a `Datetime`, then (if `time_zone` is set) localizes it as [`datetime_function`]
+/// does.
+///
+/// `input` must be `[date, time, ambiguous, non_existent]`, matching the order
+/// `datetime_from_date_time()` assembles in `dsl::functions::temporal`. A null in either `date` or
+/// `time` produces a null, same as a null numeric component does for `datetime_function`.
+pub(super) fn datetime_from_date_time_function(
+    input: &[Column],
+    time_unit: TimeUnit,
+    time_zone: Option<&TimeZone>,
+) -> PolarsResult<Column> {
+    // read the physical representation so `Date`/`Time` columns are accepted as well as plain ints
+    let date = input[0].cast(&DataType::Int32)?;
+    let date = date.i32()?;
+    let time = input[1].cast(&DataType::Int64)?;
+    let time = time.i64()?;
+    let ambiguous = input[2].str()?;
+    let non_existent = input[3].str()?;
+
+    let len = date.len();
+    let mut out = Int64ChunkedBuilder::new(PlSmallStr::from_static("datetime"), len, len);
+    let tz: Option<Tz> = time_zone.map(|tz| tz.parse()).transpose()?;
+
+    for i in 0..len {
+        let opt_naive = (|| -> Option<NaiveDateTime> {
+            let days_since_epoch = date.get(i)?;
+            let ns_since_midnight = time.get(i)?;
+            let naive_date =
+                NaiveDate::from_num_days_from_ce_opt(days_since_epoch + UNIX_EPOCH_DAY)?;
+            let naive_time = NaiveTime::from_num_seconds_from_midnight_opt(
+                (ns_since_midnight / 1_000_000_000) as u32,
+                (ns_since_midnight % 1_000_000_000) as u32,
+            )?;
+            Some(NaiveDateTime::new(naive_date, naive_time))
+        })();
+
+        let Some(naive) = opt_naive else {
+            out.append_null();
+            continue;
+        };
+
+        let resolved = resolve_naive(
+            naive,
+            tz.as_ref(),
+            ambiguous.get(i).unwrap_or("raise"),
+            non_existent.get(i).unwrap_or("raise"),
+        )?;
+
+        match resolved.and_then(|dt| naive_to_timestamp(dt, time_unit)) {
+            Some(ts) => out.append_value(ts),
+            None => out.append_null(),
+        }
+    }
+
+    Ok(out
+        .finish()
+        .into_series()
+        .cast(&DataType::Datetime(time_unit, time_zone.cloned()))?
+        .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn i32_col(name: &str, values: &[i32]) -> Column {
+        Column::new(PlSmallStr::from_str(name), values)
+    }
+
+    fn i64_col(name: &str, values: &[i64]) -> Column {
+        Column::new(PlSmallStr::from_str(name), values)
+    }
+
+    fn str_col(name: &str, values: &[&str]) -> Column {
+        Column::new(PlSmallStr::from_str(name), values)
+    }
+
+    #[test]
+    fn datetime_function_non_existent_gap_raises_by_default() {
+        let tz: TimeZone = PlSmallStr::from_static("Europe/Amsterdam");
+        // 2021-03-28 02:30:00 falls in the DST spring-forward gap in Europe/Amsterdam
+        let input = [
+            i32_col("year", &[2021]),
+            i32_col("month", &[3]),
+            i32_col("day", &[28]),
+            i32_col("hour", &[2]),
+            i32_col("minute", &[30]),
+            i32_col("second", &[0]),
+            i32_col("microsecond", &[0]),
+            str_col("ambiguous", &["raise"]),
+            str_col("non_existent", &["raise"]),
+        ];
+        let result = datetime_function(&input, TimeUnit::Microseconds, Some(&tz));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn datetime_function_non_existent_gap_nulls_when_requested() {
+        let tz: TimeZone = PlSmallStr::from_static("Europe/Amsterdam");
+        let input = [
+            i32_col("year", &[2021]),
+            i32_col("month", &[3]),
+            i32_col("day", &[28]),
+            i32_col("hour", &[2]),
+            i32_col("minute", &[30]),
+            i32_col("second", &[0]),
+            i32_col("microsecond", &[0]),
+            str_col("ambiguous", &["raise"]),
+            str_col("non_existent", &["null"]),
+        ];
+        let out = datetime_function(&input, TimeUnit::Microseconds, Some(&tz)).unwrap();
+        assert_eq!(out.null_count(), 1);
+    }
+
+    #[test]
+    fn datetime_from_iso_week_function_week_53_that_does_not_exist_is_null() {
+        // 2021 only has 52 ISO weeks
+        let input = [
+            i32_col("year", &[2021]),
+            i32_col("week", &[53]),
+            i32_col("weekday", &[1]),
+            i32_col("hour", &[0]),
+            i32_col("minute", &[0]),
+            i32_col("second", &[0]),
+            i32_col("microsecond", &[0]),
+            str_col("ambiguous", &["raise"]),
+            str_col("non_existent", &["raise"]),
+        ];
+        let out = datetime_from_iso_week_function(&input, TimeUnit::Microseconds, None).unwrap();
+        assert_eq!(out.null_count(), 1);
+    }
+
+    #[test]
+    fn datetime_from_iso_week_function_week_53_that_exists_resolves() {
+        // 2020 has 53 ISO weeks; ISO week 53, Monday falls on 2020-12-28
+        let input = [
+            i32_col("year", &[2020]),
+            i32_col("week", &[53]),
+            i32_col("weekday", &[1]),
+            i32_col("hour", &[0]),
+            i32_col("minute", &[0]),
+            i32_col("second", &[0]),
+            i32_col("microsecond", &[0]),
+            str_col("ambiguous", &["raise"]),
+            str_col("non_existent", &["raise"]),
+        ];
+        let out = datetime_from_iso_week_function(&input, TimeUnit::Microseconds, None).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2020, 12, 28)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_micros();
+        assert_eq!(out.i64().unwrap().get(0), Some(expected));
+    }
+
+    #[test]
+    fn duration_function_saturate_honors_the_dominant_term() {
+        // `weeks` is scaled by 7x what `days` is, so for equal-magnitude opposite-signed inputs the
+        // saturated result must keep the sign of the `weeks` term, not flip to the other extreme
+        let input = [
+            i64_col("weeks", &[i64::MAX]),
+            i64_col("days", &[-i64::MAX]),
+            i64_col("hours", &[0]),
+            i64_col("minutes", &[0]),
+            i64_col("seconds", &[0]),
+            i64_col("milliseconds", &[0]),
+            i64_col("microseconds", &[0]),
+            i64_col("nanoseconds", &[0]),
+        ];
+        let out = duration_function(
+            &input,
+            TimeUnit::Nanoseconds,
+            DurationOverflowBehavior::Saturate,
+        )
+        .unwrap();
+        assert_eq!(out.i64().unwrap().get(0), Some(i64::MAX));
+    }
+
+    #[test]
+    fn duration_function_overflow_raises_when_requested() {
+        let input = [
+            i64_col("weeks", &[i64::MAX]),
+            i64_col("days", &[0]),
+            i64_col("hours", &[0]),
+            i64_col("minutes", &[0]),
+            i64_col("seconds", &[0]),
+            i64_col("milliseconds", &[0]),
+            i64_col("microseconds", &[0]),
+            i64_col("nanoseconds", &[0]),
+        ];
+        let result = duration_function(
+            &input,
+            TimeUnit::Nanoseconds,
+            DurationOverflowBehavior::Raise,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn datetime_from_date_time_function_splices_date_and_time() {
+        let days_since_epoch = NaiveDate::from_ymd_opt(2024, 1, 15)
+            .unwrap()
+            .signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+            .num_days() as i32;
+        let ns_since_midnight = (10 * 3_600 + 30 * 60) * 1_000_000_000i64;
+        let input = [
+            i32_col("date", &[days_since_epoch]),
+            i64_col("time", &[ns_since_midnight]),
+            str_col("ambiguous", &["raise"]),
+            str_col("non_existent", &["raise"]),
+        ];
+        let out =
+            datetime_from_date_time_function(&input, TimeUnit::Microseconds, None).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2024, 1, 15)
+            .unwrap()
+            .and_hms_opt(10, 30, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_micros();
+        assert_eq!(out.i64().unwrap().get(0), Some(expected));
+    }
+}
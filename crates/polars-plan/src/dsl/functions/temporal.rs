@@ -46,6 +46,12 @@ pub struct DatetimeArgs {
     /// - `latest`: use the latest datetime
     /// - `null`: set to null
     pub ambiguous: Expr,
+    /// A spring-forward DST transition may cause some local times to not exist at all.
+    /// `non_existent` is a [`DataType::String`] expression that defines how to handle non-existent datetimes:
+    ///
+    /// - `raise`: (default) raise an error
+    /// - `null`: set to null
+    pub non_existent: Expr,
 }
 
 impl Default for DatetimeArgs {
@@ -61,6 +67,7 @@ impl Default for DatetimeArgs {
             time_unit: TimeUnit::Microseconds,
             time_zone: None,
             ambiguous: lit(String::from("raise")),
+            non_existent: lit(String::from("raise")),
         }
     }
 }
@@ -122,6 +129,29 @@ impl DatetimeArgs {
     pub fn with_ambiguous(self, ambiguous: Expr) -> Self {
         Self { ambiguous, ..self }
     }
+    /// # Non-existent Datetimes
+    /// A spring-forward DST transition may cause some local times to not exist at all.
+    /// `non_existent` is a [`DataType::String`] expression that defines how to handle non-existent datetimes:
+    ///
+    /// - `raise`: (default) raise an error
+    /// - `null`: set to null
+    ///
+    /// # Examples
+    /// ```ignore
+    /// // 2:30 local time does not exist in US/Eastern on the spring-forward day; map it to null
+    /// // instead of raising
+    /// let args = DatetimeArgs::new(lit(2024), lit(3), lit(10))
+    ///     .with_hms(lit(2), lit(30), lit(0))
+    ///     .with_time_zone(Some("US/Eastern".into()))
+    ///     .with_non_existent(lit("null"));
+    /// ```
+    #[cfg(feature = "timezones")]
+    pub fn with_non_existent(self, non_existent: Expr) -> Self {
+        Self {
+            non_existent,
+            ..self
+        }
+    }
 }
 
 /// Construct a column of `Datetime` from the provided [`DatetimeArgs`].
@@ -137,6 +167,7 @@ pub fn datetime(args: DatetimeArgs) -> Expr {
     let time_unit = args.time_unit;
     let time_zone = args.time_zone;
     let ambiguous = args.ambiguous;
+    let non_existent = args.non_existent;
 
     let input = vec![
         year,
@@ -147,6 +178,7 @@ pub fn datetime(args: DatetimeArgs) -> Expr {
         second,
         microsecond,
         ambiguous,
+        non_existent,
     ];
 
     Expr::Function {
@@ -166,6 +198,234 @@ pub fn datetime(args: DatetimeArgs) -> Expr {
     }
 }
 
+/// Arguments used by `datetime_from_iso_week` in order to produce an [`Expr`] of Datetime from an ISO week date
+///
+/// Construct an [`IsoWeekDateArgs`] with `IsoWeekDateArgs::new(year, week, weekday)`, where `week` is the ISO week
+/// number (1-53) and `weekday` is the ISO weekday (1 = Monday, 7 = Sunday). This will set the time components to
+/// `lit(0)`; use `with_hms` or the individual `with_*` methods to set them.
+#[derive(Debug, Clone)]
+pub struct IsoWeekDateArgs {
+    pub year: Expr,
+    pub week: Expr,
+    pub weekday: Expr,
+    pub hour: Expr,
+    pub minute: Expr,
+    pub second: Expr,
+    pub microsecond: Expr,
+    pub time_unit: TimeUnit,
+    pub time_zone: Option<TimeZone>,
+    /// See [`DatetimeArgs::ambiguous`] - defaults to `lit("raise")`.
+    pub ambiguous: Expr,
+    /// See [`DatetimeArgs::non_existent`] - defaults to `lit("raise")`.
+    pub non_existent: Expr,
+}
+
+impl IsoWeekDateArgs {
+    /// Construct a new `IsoWeekDateArgs` set to `year`, `week`, `weekday`
+    ///
+    /// Time components default to `lit(0)`; `ambiguous` and `non_existent` default to `lit("raise")`. Use the
+    /// `with_*` methods to set them.
+    pub fn new(year: Expr, week: Expr, weekday: Expr) -> Self {
+        Self {
+            year,
+            week,
+            weekday,
+            hour: lit(0),
+            minute: lit(0),
+            second: lit(0),
+            microsecond: lit(0),
+            time_unit: TimeUnit::Microseconds,
+            time_zone: None,
+            ambiguous: lit(String::from("raise")),
+            non_existent: lit(String::from("raise")),
+        }
+    }
+
+    /// Set `hour`, `minute`, and `second`
+    pub fn with_hms(self, hour: Expr, minute: Expr, second: Expr) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+            ..self
+        }
+    }
+
+    impl_unit_setter!(with_hour(hour));
+    impl_unit_setter!(with_minute(minute));
+    impl_unit_setter!(with_second(second));
+    impl_unit_setter!(with_microsecond(microsecond));
+
+    pub fn with_time_unit(self, time_unit: TimeUnit) -> Self {
+        Self { time_unit, ..self }
+    }
+    #[cfg(feature = "timezones")]
+    pub fn with_time_zone(self, time_zone: Option<TimeZone>) -> Self {
+        Self { time_zone, ..self }
+    }
+    /// See [`DatetimeArgs::with_ambiguous`].
+    #[cfg(feature = "timezones")]
+    pub fn with_ambiguous(self, ambiguous: Expr) -> Self {
+        Self { ambiguous, ..self }
+    }
+    /// See [`DatetimeArgs::with_non_existent`].
+    #[cfg(feature = "timezones")]
+    pub fn with_non_existent(self, non_existent: Expr) -> Self {
+        Self {
+            non_existent,
+            ..self
+        }
+    }
+}
+
+/// Construct a column of `Datetime` from the provided [`IsoWeekDateArgs`].
+///
+/// The resulting date is the Monday of ISO week 1 (the week containing January 4th of `year`), offset by
+/// `(week - 1) * 7 + (weekday - 1)` days. `week` must be in `1..=53` and `weekday` in `1..=7`, with Monday as
+/// `1`; an out-of-range value (including week 53 in a year with only 52 ISO weeks) produces null, as an
+/// out-of-range month or day does for [`datetime`].
+#[cfg(feature = "temporal")]
+pub fn datetime_from_iso_week(args: IsoWeekDateArgs) -> Expr {
+    let input = vec![
+        args.year,
+        args.week,
+        args.weekday,
+        args.hour,
+        args.minute,
+        args.second,
+        args.microsecond,
+        args.ambiguous,
+        args.non_existent,
+    ];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::TemporalExpr(TemporalFunction::DatetimeFromIsoWeek {
+            time_unit: args.time_unit,
+            time_zone: args.time_zone,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default()
+                | FunctionFlags::INPUT_WILDCARD_EXPANSION
+                | FunctionFlags::ALLOW_RENAME,
+            fmt_str: "datetime_from_iso_week",
+            ..Default::default()
+        },
+    }
+}
+
+/// Arguments used by `datetime_from_ordinal` in order to produce an [`Expr`] of Datetime from an ordinal date
+///
+/// Construct an [`OrdinalDateArgs`] with `OrdinalDateArgs::new(year, ordinal)`, where `ordinal` is the day of the
+/// year (1-365, or 1-366 in leap years). This will set the time components to `lit(0)`; use `with_hms` or the
+/// individual `with_*` methods to set them.
+#[derive(Debug, Clone)]
+pub struct OrdinalDateArgs {
+    pub year: Expr,
+    pub ordinal: Expr,
+    pub hour: Expr,
+    pub minute: Expr,
+    pub second: Expr,
+    pub microsecond: Expr,
+    pub time_unit: TimeUnit,
+    pub time_zone: Option<TimeZone>,
+    /// See [`DatetimeArgs::ambiguous`] - defaults to `lit("raise")`.
+    pub ambiguous: Expr,
+    /// See [`DatetimeArgs::non_existent`] - defaults to `lit("raise")`.
+    pub non_existent: Expr,
+}
+
+impl OrdinalDateArgs {
+    /// Construct a new `OrdinalDateArgs` set to `year`, `ordinal`
+    ///
+    /// Time components default to `lit(0)`; `ambiguous` and `non_existent` default to `lit("raise")`. Use the
+    /// `with_*` methods to set them.
+    pub fn new(year: Expr, ordinal: Expr) -> Self {
+        Self {
+            year,
+            ordinal,
+            hour: lit(0),
+            minute: lit(0),
+            second: lit(0),
+            microsecond: lit(0),
+            time_unit: TimeUnit::Microseconds,
+            time_zone: None,
+            ambiguous: lit(String::from("raise")),
+            non_existent: lit(String::from("raise")),
+        }
+    }
+
+    /// Set `hour`, `minute`, and `second`
+    pub fn with_hms(self, hour: Expr, minute: Expr, second: Expr) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+            ..self
+        }
+    }
+
+    impl_unit_setter!(with_hour(hour));
+    impl_unit_setter!(with_minute(minute));
+    impl_unit_setter!(with_second(second));
+    impl_unit_setter!(with_microsecond(microsecond));
+
+    pub fn with_time_unit(self, time_unit: TimeUnit) -> Self {
+        Self { time_unit, ..self }
+    }
+    #[cfg(feature = "timezones")]
+    pub fn with_time_zone(self, time_zone: Option<TimeZone>) -> Self {
+        Self { time_zone, ..self }
+    }
+    /// See [`DatetimeArgs::with_ambiguous`].
+    #[cfg(feature = "timezones")]
+    pub fn with_ambiguous(self, ambiguous: Expr) -> Self {
+        Self { ambiguous, ..self }
+    }
+    /// See [`DatetimeArgs::with_non_existent`].
+    #[cfg(feature = "timezones")]
+    pub fn with_non_existent(self, non_existent: Expr) -> Self {
+        Self {
+            non_existent,
+            ..self
+        }
+    }
+}
+
+/// Construct a column of `Datetime` from the provided [`OrdinalDateArgs`].
+///
+/// `ordinal` must be in `1..=365` (`1..=366` in leap years) and is offset from January 1st of `year`.
+#[cfg(feature = "temporal")]
+pub fn datetime_from_ordinal(args: OrdinalDateArgs) -> Expr {
+    let input = vec![
+        args.year,
+        args.ordinal,
+        args.hour,
+        args.minute,
+        args.second,
+        args.microsecond,
+        args.ambiguous,
+        args.non_existent,
+    ];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::TemporalExpr(TemporalFunction::DatetimeFromOrdinal {
+            time_unit: args.time_unit,
+            time_zone: args.time_zone,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default()
+                | FunctionFlags::INPUT_WILDCARD_EXPANSION
+                | FunctionFlags::ALLOW_RENAME,
+            fmt_str: "datetime_from_ordinal",
+            ..Default::default()
+        },
+    }
+}
+
 /// Arguments used by `duration` in order to produce an [`Expr`] of [`Duration`]
 ///
 /// To construct a [`DurationArgs`], use struct literal syntax with `..Default::default()` to leave unspecified fields at
@@ -196,6 +456,9 @@ pub struct DurationArgs {
     pub microseconds: Expr,
     pub nanoseconds: Expr,
     pub time_unit: TimeUnit,
+    /// Policy used when scaling a component into `time_unit` overflows `i64`. Defaults to
+    /// [`DurationOverflowBehavior::Wrap`].
+    pub overflow: DurationOverflowBehavior,
 }
 
 impl Default for DurationArgs {
@@ -210,6 +473,7 @@ impl Default for DurationArgs {
             microseconds: lit(0),
             nanoseconds: lit(0),
             time_unit: TimeUnit::Microseconds,
+            overflow: DurationOverflowBehavior::default(),
         }
     }
 }
@@ -268,6 +532,24 @@ impl DurationArgs {
     impl_unit_setter!(with_milliseconds(milliseconds));
     impl_unit_setter!(with_microseconds(microseconds));
     impl_unit_setter!(with_nanoseconds(nanoseconds));
+
+    /// Set the policy used when scaling a component into `time_unit` overflows `i64`
+    ///
+    /// # Examples
+    /// ```
+    /// use polars_plan::prelude::*;
+    /// // saturate instead of silently wrapping if `num_weeks` is large enough to overflow i64
+    /// // nanoseconds once scaled
+    /// let args = DurationArgs {
+    ///     weeks: col("num_weeks"),
+    ///     time_unit: TimeUnit::Nanoseconds,
+    ///     ..Default::default()
+    /// }
+    /// .with_overflow(DurationOverflowBehavior::Saturate);
+    /// ```
+    pub fn with_overflow(self, overflow: DurationOverflowBehavior) -> Self {
+        Self { overflow, ..self }
+    }
 }
 
 /// Construct a column of [`Duration`] from the provided [`DurationArgs`]
@@ -284,7 +566,10 @@ pub fn duration(args: DurationArgs) -> Expr {
             args.microseconds,
             args.nanoseconds,
         ],
-        function: FunctionExpr::TemporalExpr(TemporalFunction::Duration(args.time_unit)),
+        function: FunctionExpr::TemporalExpr(TemporalFunction::Duration {
+            time_unit: args.time_unit,
+            overflow: args.overflow,
+        }),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ElementWise,
             flags: FunctionFlags::default() | FunctionFlags::INPUT_WILDCARD_EXPANSION,
@@ -292,3 +577,191 @@ pub fn duration(args: DurationArgs) -> Expr {
         },
     }
 }
+
+/// Arguments used by `datetime_from_date_time` in order to produce an [`Expr`] of Datetime by combining a
+/// `Date`-typed [`Expr`] with a `Time`-typed [`Expr`]
+///
+/// Construct a [`DateAndTimeArgs`] with `DateAndTimeArgs::new(date, time)`. `ambiguous` and `non_existent` default
+/// to `lit("raise")`, matching [`DatetimeArgs`]; use `with_ambiguous`/`with_non_existent` to change them.
+///
+/// # Examples
+/// ```
+/// use polars_plan::prelude::*;
+/// // splice a Date column with a Time column instead of round-tripping through dt.year()/.hour()/...
+/// let args = DateAndTimeArgs::new(col("date"), col("time"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateAndTimeArgs {
+    pub date: Expr,
+    pub time: Expr,
+    pub time_unit: TimeUnit,
+    pub time_zone: Option<TimeZone>,
+    pub ambiguous: Expr,
+    pub non_existent: Expr,
+}
+
+impl DateAndTimeArgs {
+    /// Construct a new `DateAndTimeArgs` combining `date` and `time`
+    pub fn new(date: Expr, time: Expr) -> Self {
+        Self {
+            date,
+            time,
+            time_unit: TimeUnit::Microseconds,
+            time_zone: None,
+            ambiguous: lit(String::from("raise")),
+            non_existent: lit(String::from("raise")),
+        }
+    }
+
+    pub fn with_time_unit(self, time_unit: TimeUnit) -> Self {
+        Self { time_unit, ..self }
+    }
+    #[cfg(feature = "timezones")]
+    pub fn with_time_zone(self, time_zone: Option<TimeZone>) -> Self {
+        Self { time_zone, ..self }
+    }
+    #[cfg(feature = "timezones")]
+    pub fn with_ambiguous(self, ambiguous: Expr) -> Self {
+        Self { ambiguous, ..self }
+    }
+    #[cfg(feature = "timezones")]
+    pub fn with_non_existent(self, non_existent: Expr) -> Self {
+        Self {
+            non_existent,
+            ..self
+        }
+    }
+}
+
+/// Construct a column of `Datetime` by combining the `Date` and `Time` columns in the provided
+/// [`DateAndTimeArgs`], analogous to `and_time` in other time-zone libraries.
+///
+/// Takes the days-since-epoch from `date`, adds the intra-day offset from `time` at the requested `time_unit`
+/// resolution, then localizes against `time_zone` if set. A null in either `date` or `time` produces a null.
+#[cfg(feature = "temporal")]
+pub fn datetime_from_date_time(args: DateAndTimeArgs) -> Expr {
+    let input = vec![args.date, args.time, args.ambiguous, args.non_existent];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::TemporalExpr(TemporalFunction::DatetimeFromDateTime {
+            time_unit: args.time_unit,
+            time_zone: args.time_zone,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ElementWise,
+            flags: FunctionFlags::default()
+                | FunctionFlags::INPUT_WILDCARD_EXPANSION
+                | FunctionFlags::ALLOW_RENAME,
+            fmt_str: "datetime_from_date_time",
+            ..Default::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function_input(expr: Expr) -> Vec<Expr> {
+        match expr {
+            Expr::Function { input, .. } => input,
+            _ => panic!("expected Expr::Function"),
+        }
+    }
+
+    #[test]
+    fn datetime_args_default_non_existent_is_raise() {
+        let args = DatetimeArgs::new(lit(2024), lit(1), lit(1));
+        assert_eq!(args.non_existent, lit(String::from("raise")));
+    }
+
+    #[test]
+    fn datetime_threads_non_existent_into_input() {
+        let args = DatetimeArgs::new(lit(2024), lit(1), lit(1)).with_non_existent(lit("null"));
+        let input = function_input(datetime(args));
+        // year, month, day, hour, minute, second, microsecond, ambiguous, non_existent
+        assert_eq!(input.len(), 9);
+        assert_eq!(input[8], lit("null"));
+    }
+
+    #[test]
+    fn iso_week_date_args_default_ambiguous_and_non_existent_are_raise() {
+        let args = IsoWeekDateArgs::new(lit(2024), lit(1), lit(1));
+        assert_eq!(args.ambiguous, lit(String::from("raise")));
+        assert_eq!(args.non_existent, lit(String::from("raise")));
+    }
+
+    #[test]
+    fn datetime_from_iso_week_threads_ambiguous_and_non_existent_into_input() {
+        let args = IsoWeekDateArgs::new(lit(2024), lit(1), lit(1))
+            .with_ambiguous(lit("earliest"))
+            .with_non_existent(lit("null"));
+        let input = function_input(datetime_from_iso_week(args));
+        // year, week, weekday, hour, minute, second, microsecond, ambiguous, non_existent
+        assert_eq!(input.len(), 9);
+        assert_eq!(input[7], lit("earliest"));
+        assert_eq!(input[8], lit("null"));
+    }
+
+    #[test]
+    fn ordinal_date_args_default_ambiguous_and_non_existent_are_raise() {
+        let args = OrdinalDateArgs::new(lit(2024), lit(1));
+        assert_eq!(args.ambiguous, lit(String::from("raise")));
+        assert_eq!(args.non_existent, lit(String::from("raise")));
+    }
+
+    #[test]
+    fn datetime_from_ordinal_threads_ambiguous_and_non_existent_into_input() {
+        let args = OrdinalDateArgs::new(lit(2024), lit(60)).with_non_existent(lit("null"));
+        let input = function_input(datetime_from_ordinal(args));
+        // year, ordinal, hour, minute, second, microsecond, ambiguous, non_existent
+        assert_eq!(input.len(), 8);
+        assert_eq!(input[7], lit("null"));
+    }
+
+    #[test]
+    fn duration_args_default_overflow_is_wrap() {
+        let args = DurationArgs::new();
+        assert_eq!(args.overflow, DurationOverflowBehavior::Wrap);
+    }
+
+    fn duration_function(expr: Expr) -> TemporalFunction {
+        match expr {
+            Expr::Function { function, .. } => match function {
+                FunctionExpr::TemporalExpr(f) => f,
+                _ => panic!("expected FunctionExpr::TemporalExpr"),
+            },
+            _ => panic!("expected Expr::Function"),
+        }
+    }
+
+    #[test]
+    fn duration_threads_overflow_into_function() {
+        let args = DurationArgs::new()
+            .with_weeks(lit(1))
+            .with_overflow(DurationOverflowBehavior::Saturate);
+        match duration_function(duration(args)) {
+            TemporalFunction::Duration { overflow, .. } => {
+                assert_eq!(overflow, DurationOverflowBehavior::Saturate);
+            },
+            other => panic!("expected TemporalFunction::Duration, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn date_and_time_args_default_ambiguous_and_non_existent_are_raise() {
+        let args = DateAndTimeArgs::new(col("date"), col("time"));
+        assert_eq!(args.ambiguous, lit(String::from("raise")));
+        assert_eq!(args.non_existent, lit(String::from("raise")));
+    }
+
+    #[test]
+    fn datetime_from_date_time_threads_args_into_input() {
+        let args = DateAndTimeArgs::new(col("date"), col("time")).with_non_existent(lit("null"));
+        let input = function_input(datetime_from_date_time(args));
+        // date, time, ambiguous, non_existent
+        assert_eq!(input.len(), 4);
+        assert_eq!(input[3], lit("null"));
+    }
+}